@@ -1,41 +1,132 @@
+use crate::readability::Readability;
 use crate::{SeoReport, SeoIssue, IssueSeverity};
 use scraper::{Html, Selector};
 use std::collections::HashMap;
 use url::Url;
 
+/// User-agent usado pelo analisador ao consultar o `robots.txt`.
+const USER_AGENT: &str = "fast_seo";
+
 pub struct SeoAnalyzer {
     client: reqwest::Client,
+    user_agent: String,
+}
+
+impl Default for SeoAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SeoAnalyzer {
     pub fn new() -> Self {
+        // A medição de compressão depende de ver o corpo como ele chega na
+        // rede: se o reqwest descomprimir automaticamente, ele remove o
+        // `Content-Encoding` e `transfer_size` passa a igualar
+        // `decompressed_size`. Desligamos a descompressão automática
+        // explicitamente e tratamos `Content-Encoding` no `performance`.
+        let client = reqwest::Client::builder()
+            .no_gzip()
+            .no_brotli()
+            .no_deflate()
+            .no_zstd()
+            .build()
+            .expect("cliente HTTP padrão");
         Self {
-            client: reqwest::Client::new(),
+            client,
+            user_agent: USER_AGENT.to_string(),
         }
     }
 
+    /// Cliente HTTP compartilhado, para que chamadores como o crawler
+    /// reaproveitem a mesma conexão em vez de abrir um novo.
+    pub(crate) fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
     pub async fn analyze_url(&self, url: &str) -> Result<SeoReport, Box<dyn std::error::Error>> {
+        // Comporta-se como um crawler bem-educado: consulta o robots.txt do
+        // host antes de buscar a página. Um robots.txt ausente é tratado
+        // como "tudo permitido".
+        let robots = crate::robots::RobotsTxt::fetch(&self.client, url)
+            .await
+            .unwrap_or_default();
+        let (report, _links) = self.analyze_with_robots(url, &robots).await?;
+        Ok(report)
+    }
+
+    /// Busca a página uma única vez e devolve o relatório SEO junto com os
+    /// links internos (mesmo host) descobertos no mesmo documento, usando
+    /// um `robots.txt` já carregado.
+    ///
+    /// O [`crate::crawler::Crawler`] usa este caminho para evitar refazer o
+    /// download do `robots.txt` e da própria página a cada URL.
+    pub async fn analyze_with_robots(
+        &self,
+        url: &str,
+        robots: &crate::robots::RobotsTxt,
+    ) -> Result<(SeoReport, Vec<String>), Box<dyn std::error::Error>> {
         let start_time = std::time::Instant::now();
-        
-        let response = self.client.get(url).send().await?;
-        let html_content = response.text().await?;
+
+        let allowed = robots.is_allowed(&self.user_agent, url);
+
+        let response = self
+            .client
+            .get(url)
+            .header(
+                reqwest::header::ACCEPT_ENCODING,
+                crate::performance::ACCEPT_ENCODING,
+            )
+            .send()
+            .await?;
+
+        let content_encoding = response
+            .headers()
+            .get(reqwest::header::CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let body = response.bytes().await?;
+        let transfer_size = body.len() as u64;
+        let decoded =
+            crate::performance::PerformanceAnalyzer::decode(content_encoding.as_deref(), &body)?;
+        let decompressed_size = decoded.len() as u64;
+        let html_content = String::from_utf8_lossy(&decoded).into_owned();
         let load_time = start_time.elapsed().as_secs_f64();
-        
+
+        let performance = crate::performance::PerformanceReport::new(
+            transfer_size,
+            decompressed_size,
+            content_encoding,
+        );
+
         let document = Html::parse_document(&html_content);
-        
+
+        // Isola o conteúdo principal antes de medir densidade de palavras,
+        // para que nomes de tags, scripts e navegação não poluam os números.
+        let article_text = Readability::extract_article_text(&document);
+        let word_count = article_text.split_whitespace().count();
+
         let mut report = SeoReport {
             url: url.to_string(),
             title: self.extract_title(&document),
             meta_description: self.extract_meta_description(&document),
             h1_tags: self.extract_h1_tags(&document),
             h2_tags: self.extract_h2_tags(&document),
-            keyword_density: self.calculate_keyword_density(&html_content),
+            keyword_density: self.calculate_keyword_density(&article_text),
             images_without_alt: self.count_images_without_alt(&document),
             internal_links: 0,
             external_links: 0,
-            page_size: Some(html_content.len() as u64),
+            page_size: Some(decompressed_size),
             load_time: Some(load_time),
+            performance: Some(performance.clone()),
             structured_data: self.extract_structured_data(&document),
+            structured_data_items: Vec::new(),
             issues: Vec::new(),
             score: 0,
         };
@@ -44,10 +135,37 @@ impl SeoAnalyzer {
         report.internal_links = internal;
         report.external_links = external;
 
+        let structured = crate::structured_data::StructuredDataAnalyzer::analyze(
+            &report.structured_data,
+        );
+        report.structured_data_items = structured.items;
+
         report.issues = self.generate_issues(&report);
+        report.issues.extend(structured.issues);
+        if word_count < 300 {
+            report.issues.push(SeoIssue {
+                severity: IssueSeverity::Warning,
+                message: format!("Conteúdo raso ({} palavras)", word_count),
+                recommendation: "Páginas com pouco texto tendem a ranquear mal; amplie o conteúdo para mais de 300 palavras".to_string(),
+            });
+        }
+        if let Some(issue) = crate::performance::PerformanceAnalyzer::compression_issue(
+            &performance,
+            content_type.as_deref(),
+        ) {
+            report.issues.push(issue);
+        }
+        if !allowed {
+            report.issues.push(SeoIssue {
+                severity: IssueSeverity::Critical,
+                message: "Página bloqueada pelo robots.txt".to_string(),
+                recommendation: "Libere a URL no robots.txt para que ela possa ser rastreada e indexada".to_string(),
+            });
+        }
         report.score = self.calculate_score(&report);
 
-        Ok(report)
+        let links = self.collect_internal_links(&document, url)?;
+        Ok((report, links))
     }
 
     fn extract_title(&self, document: &Html) -> Option<String> {
@@ -83,28 +201,12 @@ impl SeoAnalyzer {
             .collect()
     }
 
-    fn calculate_keyword_density(&self, html_content: &str) -> HashMap<String, f32> {
-        let text = html_content
-            .chars()
-            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
-            .collect::<String>();
-        
-        let words: Vec<&str> = text
-            .split_whitespace()
-            .filter(|word| word.len() > 3)
-            .collect();
-
-        let total_words = words.len() as f32;
-        let mut word_count = HashMap::new();
-
-        for word in words {
-            let word = word.to_lowercase();
-            *word_count.entry(word).or_insert(0) += 1;
-        }
-
-        word_count
+    fn calculate_keyword_density(&self, content: &str) -> HashMap<String, f32> {
+        let analysis = crate::keywords::Tokenizer::default().analyze(content);
+        analysis
+            .keywords
             .into_iter()
-            .map(|(word, count)| (word, (count as f32 / total_words) * 100.0))
+            .map(|k| (k.term, k.density))
             .collect()
     }
 
@@ -119,7 +221,7 @@ impl SeoAnalyzer {
     fn count_links(&self, document: &Html, base_url: &str) -> Result<(u32, u32), Box<dyn std::error::Error>> {
         let selector = Selector::parse("a[href]").unwrap();
         let base = Url::parse(base_url)?;
-        
+
         let mut internal = 0;
         let mut external = 0;
 
@@ -138,6 +240,41 @@ impl SeoAnalyzer {
         Ok((internal, external))
     }
 
+    /// Busca uma página e retorna seus links internos (mesmo host),
+    /// normalizados como URLs absolutas sem fragmento. Usado pelo
+    /// [`crate::crawler::Crawler`] para descobrir páginas a visitar.
+    pub async fn discover_internal_links(
+        &self,
+        url: &str,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let html = self.client.get(url).send().await?.text().await?;
+        let document = Html::parse_document(&html);
+        self.collect_internal_links(&document, url)
+    }
+
+    fn collect_internal_links(
+        &self,
+        document: &Html,
+        base_url: &str,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let selector = Selector::parse("a[href]").unwrap();
+        let base = Url::parse(base_url)?;
+
+        let mut links = Vec::new();
+        for element in document.select(&selector) {
+            if let Some(href) = element.value().attr("href") {
+                if let Ok(mut joined) = base.join(href) {
+                    if joined.host() == base.host() {
+                        joined.set_fragment(None);
+                        links.push(joined.into());
+                    }
+                }
+            }
+        }
+
+        Ok(links)
+    }
+
     fn extract_structured_data(&self, document: &Html) -> Vec<String> {
         let selector = Selector::parse("script[type='application/ld+json']").unwrap();
         document
@@ -223,7 +360,7 @@ impl SeoAnalyzer {
     }
 
     fn calculate_score(&self, report: &SeoReport) -> u32 {
-        let mut score = 100;
+        let mut score: u32 = 100;
 
         for issue in &report.issues {
             match issue.severity {
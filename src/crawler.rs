@@ -0,0 +1,331 @@
+use crate::analyzer::SeoAnalyzer;
+use crate::robots::RobotsTxt;
+use crate::sitemap::{FetchLimits, SitemapGenerator};
+use crate::{IssueSeverity, SeoIssue, SeoReport};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore};
+use tokio::time::{sleep_until, Instant};
+
+/// Espaça os pedidos a um mesmo host respeitando o `Crawl-delay`.
+///
+/// Cada tarefa reserva o próximo intervalo livre e só então dorme até ele,
+/// de modo que pedidos concorrentes fiquem realmente distribuídos no tempo
+/// em vez de dispararem todos juntos após um único `sleep`.
+struct Pacer {
+    delay: Option<Duration>,
+    next: Mutex<Option<Instant>>,
+}
+
+impl Pacer {
+    fn new(delay: Option<Duration>) -> Self {
+        Self {
+            delay,
+            next: Mutex::new(None),
+        }
+    }
+
+    async fn tick(&self) {
+        let Some(delay) = self.delay else {
+            return;
+        };
+        let at = {
+            let mut next = self.next.lock().await;
+            let now = Instant::now();
+            let at = next.map(|t| t.max(now)).unwrap_or(now);
+            *next = Some(at + delay);
+            at
+        };
+        sleep_until(at).await;
+    }
+}
+
+/// Resultado da auditoria de um site inteiro.
+#[derive(Debug, Clone)]
+pub struct SiteReport {
+    pub seed: String,
+    pub pages: Vec<SeoReport>,
+    /// Problemas de âmbito do site (títulos duplicados, páginas órfãs, ...).
+    pub issues: Vec<SeoIssue>,
+}
+
+/// Rastreador recursivo que audita todas as páginas de mesmo host
+/// alcançáveis a partir de uma URL semente.
+pub struct Crawler {
+    analyzer: Arc<SeoAnalyzer>,
+    user_agent: String,
+    max_depth: usize,
+    max_pages: usize,
+    concurrency: usize,
+}
+
+impl Crawler {
+    pub fn new() -> Self {
+        Self {
+            analyzer: Arc::new(SeoAnalyzer::new()),
+            user_agent: "fast_seo".to_string(),
+            max_depth: 3,
+            max_pages: 100,
+            concurrency: 8,
+        }
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = pages;
+        self
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Rastreia o site a partir de `seed`, analisando cada página
+    /// alcançável de mesmo host até os limites configurados.
+    pub async fn crawl(&self, seed: &str) -> Result<SiteReport, Box<dyn std::error::Error>> {
+        // O robots.txt é baixado uma única vez e compartilhado com cada
+        // análise de página, em vez de refeito a cada URL.
+        let robots = RobotsTxt::fetch(self.analyzer.client(), seed)
+            .await
+            .unwrap_or_default();
+        let crawl_delay = robots
+            .crawl_delay(&self.user_agent)
+            .map(Duration::from_secs_f64);
+
+        // URLs declaradas nos sitemaps anunciados pelo robots.txt; usadas
+        // como fonte independente do grafo de links para detectar páginas
+        // órfãs (declaradas, mas sem nenhum link interno apontando para
+        // elas).
+        let declared = self.declared_urls(&robots).await;
+
+        let robots = Arc::new(robots);
+        let pacer = Arc::new(Pacer::new(crawl_delay));
+
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut frontier: Vec<String> = vec![seed.to_string()];
+        visited.insert(seed.to_string());
+
+        let mut pages: Vec<SeoReport> = Vec::new();
+        // Links de saída por página, para detectar páginas órfãs.
+        let mut outbound: HashMap<String, Vec<String>> = HashMap::new();
+
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+
+        for _ in 0..=self.max_depth {
+            if frontier.is_empty() || pages.len() >= self.max_pages {
+                break;
+            }
+
+            let mut handles = Vec::new();
+            for url in std::mem::take(&mut frontier) {
+                if pages.len() + handles.len() >= self.max_pages {
+                    break;
+                }
+                let analyzer = Arc::clone(&self.analyzer);
+                let semaphore = Arc::clone(&semaphore);
+                let pacer = Arc::clone(&pacer);
+                let robots = Arc::clone(&robots);
+                handles.push(tokio::spawn(async move {
+                    let _permit = semaphore.acquire_owned().await.ok()?;
+                    // Espaça o início do pedido conforme o Crawl-delay e
+                    // busca a página uma só vez (relatório + links no mesmo
+                    // fetch).
+                    pacer.tick().await;
+                    let (report, links) =
+                        analyzer.analyze_with_robots(&url, &robots).await.ok()?;
+                    Some((url, report, links))
+                }));
+            }
+
+            let mut next: Vec<String> = Vec::new();
+            for handle in handles {
+                if let Ok(Some((url, report, links))) = handle.await {
+                    pages.push(report);
+                    for link in &links {
+                        if !visited.contains(link) {
+                            visited.insert(link.clone());
+                            next.push(link.clone());
+                        }
+                    }
+                    outbound.insert(url, links);
+                }
+            }
+
+            frontier = next;
+        }
+
+        let issues = Self::aggregate_issues(seed, &pages, &outbound, &declared);
+        Ok(SiteReport {
+            seed: seed.to_string(),
+            pages,
+            issues,
+        })
+    }
+
+    /// Coleta as URLs declaradas nos sitemaps anunciados pelo `robots.txt`.
+    ///
+    /// Serve de universo independente do frontier de rastreamento: uma URL
+    /// que o site publica no sitemap mas para a qual nenhuma página aponta
+    /// é uma candidata a órfã. Falhas de rede são ignoradas silenciosamente.
+    async fn declared_urls(&self, robots: &RobotsTxt) -> HashSet<String> {
+        let mut declared = HashSet::new();
+        let limits = FetchLimits::default();
+        for sitemap_url in &robots.sitemaps {
+            if let Ok(sitemap) = SitemapGenerator::fetch_sitemap_with(
+                self.analyzer.client(),
+                sitemap_url,
+                &limits,
+            )
+            .await
+            {
+                for url in sitemap.urls {
+                    declared.insert(url.loc);
+                }
+            }
+        }
+        declared
+    }
+
+    fn aggregate_issues(
+        seed: &str,
+        pages: &[SeoReport],
+        outbound: &HashMap<String, Vec<String>>,
+        declared: &HashSet<String>,
+    ) -> Vec<SeoIssue> {
+        let mut issues = Vec::new();
+
+        // Títulos duplicados.
+        Self::report_duplicates(
+            pages.iter().filter_map(|p| p.title.clone()),
+            "Título duplicado em várias páginas",
+            "Use títulos únicos em cada página para evitar canibalização de palavras-chave",
+            &mut issues,
+        );
+
+        // Meta descriptions duplicadas.
+        Self::report_duplicates(
+            pages.iter().filter_map(|p| p.meta_description.clone()),
+            "Meta description duplicada em várias páginas",
+            "Escreva uma meta description única para cada página",
+            &mut issues,
+        );
+
+        // Páginas órfãs: URLs que o site declara no sitemap mas para as
+        // quais nenhum link interno descoberto durante o rastreamento
+        // aponta. Páginas alcançadas pelo crawler sempre têm ao menos um
+        // link de entrada (foi assim que chegaram ao frontier), então o
+        // sitemap é a fonte independente necessária para flagrar órfãs.
+        let mut inbound: HashSet<String> = HashSet::new();
+        for links in outbound.values() {
+            for link in links {
+                inbound.insert(link.clone());
+            }
+        }
+        let mut orphans: Vec<&String> = declared
+            .iter()
+            .filter(|url| **url != seed && !inbound.contains(*url))
+            .collect();
+        orphans.sort();
+        for url in orphans {
+            issues.push(SeoIssue {
+                severity: IssueSeverity::Warning,
+                message: format!("Página órfã: {}", url),
+                recommendation: "Adicione links internos apontando para esta página declarada no sitemap".to_string(),
+            });
+        }
+
+        issues
+    }
+
+    fn report_duplicates<I: IntoIterator<Item = String>>(
+        values: I,
+        message: &str,
+        recommendation: &str,
+        issues: &mut Vec<SeoIssue>,
+    ) {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for value in values {
+            *counts.entry(value).or_insert(0) += 1;
+        }
+        if counts.values().any(|&c| c > 1) {
+            issues.push(SeoIssue {
+                severity: IssueSeverity::Warning,
+                message: message.to_string(),
+                recommendation: recommendation.to_string(),
+            });
+        }
+    }
+}
+
+impl Default for Crawler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(url: &str) -> SeoReport {
+        SeoReport {
+            url: url.to_string(),
+            title: None,
+            meta_description: None,
+            h1_tags: Vec::new(),
+            h2_tags: Vec::new(),
+            keyword_density: HashMap::new(),
+            images_without_alt: 0,
+            internal_links: 0,
+            external_links: 0,
+            page_size: None,
+            load_time: None,
+            performance: None,
+            structured_data: Vec::new(),
+            structured_data_items: Vec::new(),
+            issues: Vec::new(),
+            score: 0,
+        }
+    }
+
+    #[test]
+    fn sitemap_url_without_inbound_links_is_orphan() {
+        let seed = "https://site.com/";
+        let pages = vec![report(seed), report("https://site.com/a")];
+        // A semente aponta para /a, mas nada aponta para /orphan.
+        let mut outbound = HashMap::new();
+        outbound.insert(seed.to_string(), vec!["https://site.com/a".to_string()]);
+        let declared: HashSet<String> = [
+            seed.to_string(),
+            "https://site.com/a".to_string(),
+            "https://site.com/orphan".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let issues = Crawler::aggregate_issues(seed, &pages, &outbound, &declared);
+        let orphan_msgs: Vec<_> = issues
+            .iter()
+            .filter(|i| i.message.starts_with("Página órfã"))
+            .collect();
+        assert_eq!(orphan_msgs.len(), 1);
+        assert!(orphan_msgs[0].message.contains("/orphan"));
+    }
+
+    #[test]
+    fn no_orphans_without_a_declared_sitemap() {
+        let seed = "https://site.com/";
+        let pages = vec![report(seed), report("https://site.com/a")];
+        let mut outbound = HashMap::new();
+        outbound.insert(seed.to_string(), vec!["https://site.com/a".to_string()]);
+
+        let issues = Crawler::aggregate_issues(seed, &pages, &outbound, &HashSet::new());
+        assert!(!issues.iter().any(|i| i.message.starts_with("Página órfã")));
+    }
+}
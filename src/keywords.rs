@@ -0,0 +1,198 @@
+use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Frequência e densidade de um termo (radical).
+#[derive(Debug, Clone)]
+pub struct KeywordStat {
+    pub term: String,
+    pub count: usize,
+    /// Densidade em porcentagem do total de palavras de conteúdo.
+    pub density: f32,
+}
+
+/// Frequência de uma frase de `n` palavras.
+#[derive(Debug, Clone)]
+pub struct PhraseStat {
+    pub phrase: String,
+    pub count: usize,
+    pub n: usize,
+}
+
+/// Resultado da análise de palavras-chave de um texto.
+#[derive(Debug, Clone)]
+pub struct KeywordAnalysis {
+    pub keywords: Vec<KeywordStat>,
+    pub phrases: Vec<PhraseStat>,
+    pub total_words: usize,
+}
+
+/// Tokenizador que normaliza, remove stopwords e aplica stemming antes
+/// de medir frequência e densidade de palavras-chave.
+pub struct Tokenizer {
+    stopwords: std::collections::HashSet<String>,
+    stemmer: Stemmer,
+    top_n: usize,
+}
+
+impl Tokenizer {
+    /// Cria um tokenizador que retorna os `top_n` termos e frases.
+    pub fn new(top_n: usize) -> Self {
+        let stopwords = STOPWORDS_EN
+            .iter()
+            .chain(STOPWORDS_PT.iter())
+            .map(|s| s.to_string())
+            .collect();
+
+        Self {
+            stopwords,
+            stemmer: Stemmer::create(Algorithm::English),
+            top_n,
+        }
+    }
+
+    /// Segmenta o texto em palavras (limites Unicode), em minúsculas,
+    /// descartando pontuação e tokens de uma só letra.
+    fn words(&self, text: &str) -> Vec<String> {
+        text.unicode_words()
+            .map(|w| w.to_lowercase())
+            .filter(|w| w.chars().count() > 1)
+            .collect()
+    }
+
+    /// Aplica stemming a um termo de conteúdo.
+    fn stem(&self, word: &str) -> String {
+        self.stemmer.stem(word).into_owned()
+    }
+
+    /// Analisa o texto, retornando os principais termos e frases (2- e
+    /// 3-gramas) por frequência, com densidade relativa ao total de
+    /// palavras de conteúdo.
+    pub fn analyze(&self, text: &str) -> KeywordAnalysis {
+        let words = self.words(text);
+        let total_words = words.len();
+
+        // Sequência de radicais de conteúdo (sem stopwords), preservando a
+        // ordem para a extração de n-gramas.
+        let content: Vec<String> = words
+            .iter()
+            .filter(|w| !self.stopwords.contains(*w))
+            .map(|w| self.stem(w))
+            .collect();
+
+        let mut term_counts: HashMap<String, usize> = HashMap::new();
+        for term in &content {
+            *term_counts.entry(term.clone()).or_insert(0) += 1;
+        }
+
+        // Densidade relativa ao total de palavras de conteúdo (sem
+        // stopwords), como o SEO costuma reportar.
+        let denom = content.len().max(1) as f32;
+        let mut keywords: Vec<KeywordStat> = term_counts
+            .into_iter()
+            .map(|(term, count)| KeywordStat {
+                term,
+                count,
+                density: count as f32 / denom * 100.0,
+            })
+            .collect();
+        keywords.sort_by(|a, b| b.count.cmp(&a.count).then(a.term.cmp(&b.term)));
+        keywords.truncate(self.top_n);
+
+        let mut phrases = self.ngrams(&content, 2);
+        phrases.extend(self.ngrams(&content, 3));
+
+        KeywordAnalysis {
+            keywords,
+            phrases,
+            total_words,
+        }
+    }
+
+    fn ngrams(&self, content: &[String], n: usize) -> Vec<PhraseStat> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for window in content.windows(n) {
+            *counts.entry(window.join(" ")).or_insert(0) += 1;
+        }
+
+        let mut phrases: Vec<PhraseStat> = counts
+            .into_iter()
+            .filter(|(_, c)| *c > 1)
+            .map(|(phrase, count)| PhraseStat { phrase, count, n })
+            .collect();
+        phrases.sort_by(|a, b| b.count.cmp(&a.count).then(a.phrase.cmp(&b.phrase)));
+        phrases.truncate(self.top_n);
+        phrases
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Self {
+        Self::new(20)
+    }
+}
+
+const STOPWORDS_EN: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "all", "any", "can", "her", "was", "one",
+    "our", "out", "day", "get", "has", "him", "his", "how", "man", "new", "now", "old", "see",
+    "two", "way", "who", "boy", "did", "its", "let", "put", "say", "she", "too", "use", "that",
+    "this", "with", "have", "from", "they", "will", "would", "there", "their", "what", "about",
+    "which", "when", "were", "your", "been", "more", "them", "then", "than", "into", "some",
+    "could", "other", "these", "also", "only", "over", "such", "just", "here",
+];
+
+const STOPWORDS_PT: &[&str] = &[
+    "que", "com", "uma", "para", "por", "dos", "das", "mas", "não", "nao", "como", "mais", "foi",
+    "são", "sao", "tem", "seu", "sua", "ser", "ele", "ela", "nos", "nós", "você", "voce", "isso",
+    "esse", "essa", "este", "esta", "isto", "pelo", "pela", "pelos", "pelas", "num", "numa", "sem",
+    "sob", "sobre", "entre", "também", "tambem", "muito", "quando", "porque", "até", "ate", "aos",
+    "das", "dos", "era", "eram", "está", "esta", "estão", "estao",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stems_inflections_to_one_key() {
+        let analysis = Tokenizer::default().analyze("running runs run runs");
+        let run = analysis
+            .keywords
+            .iter()
+            .find(|k| k.term == "run")
+            .expect("radical run presente");
+        assert_eq!(run.count, 4);
+    }
+
+    #[test]
+    fn drops_english_and_portuguese_stopwords() {
+        let analysis = Tokenizer::default().analyze("the content and also para que conteudo");
+        assert!(analysis.keywords.iter().all(|k| k.term != "the"));
+        assert!(analysis.keywords.iter().all(|k| k.term != "que"));
+        assert!(analysis.keywords.iter().any(|k| k.term.starts_with("content")));
+    }
+
+    #[test]
+    fn density_is_percentage_of_content_words() {
+        // 4 palavras no total; "the"/"and" são stopwords, sobrando 2
+        // palavras de conteúdo ("cat"/"cats" colapsam em "cat").
+        let analysis = Tokenizer::default().analyze("cat cats the and");
+        assert_eq!(analysis.total_words, 4);
+        let cat = analysis.keywords.iter().find(|k| k.term == "cat").unwrap();
+        assert_eq!(cat.count, 2);
+        assert!((cat.density - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn extracts_repeated_ngrams() {
+        let analysis =
+            Tokenizer::default().analyze("fast search engine fast search engine fast search engine");
+        let bigram = analysis
+            .phrases
+            .iter()
+            .find(|p| p.n == 2 && p.phrase == "fast search");
+        assert!(bigram.is_some());
+        assert!(bigram.unwrap().count >= 2);
+        assert!(analysis.phrases.iter().any(|p| p.n == 3));
+    }
+}
@@ -1,5 +1,8 @@
 pub mod analyzer;
+pub mod crawler;
 pub mod meta;
+pub mod readability;
+pub mod robots;
 pub mod sitemap;
 pub mod keywords;
 pub mod performance;
@@ -21,7 +24,9 @@ pub struct SeoReport {
     pub external_links: u32,
     pub page_size: Option<u64>,
     pub load_time: Option<f64>,
+    pub performance: Option<performance::PerformanceReport>,
     pub structured_data: Vec<String>,
+    pub structured_data_items: Vec<structured_data::StructuredDataItem>,
     pub issues: Vec<SeoIssue>,
     pub score: u32,
 }
@@ -0,0 +1,175 @@
+use crate::{IssueSeverity, SeoIssue};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// Cabeçalho `Accept-Encoding` anunciado pelo analisador.
+pub const ACCEPT_ENCODING: &str = "gzip, deflate, br, zstd";
+
+/// Respostas de texto abaixo deste tamanho (bytes) não geram alerta de
+/// compressão — o ganho seria desprezível.
+const COMPRESSION_MIN_SIZE: u64 = 1_024;
+
+/// Métricas de peso e eficiência de transferência de uma página.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceReport {
+    /// Bytes efetivamente trafegados (corpo na rede).
+    pub transfer_size: u64,
+    /// Bytes após descompressão.
+    pub decompressed_size: u64,
+    /// Codificação de conteúdo declarada pela resposta, se houver.
+    pub content_encoding: Option<String>,
+    /// Razão de compressão (`decompressed_size / transfer_size`).
+    pub compression_ratio: f32,
+}
+
+impl PerformanceReport {
+    pub fn new(
+        transfer_size: u64,
+        decompressed_size: u64,
+        content_encoding: Option<String>,
+    ) -> Self {
+        let compression_ratio = if transfer_size == 0 {
+            1.0
+        } else {
+            decompressed_size as f32 / transfer_size as f32
+        };
+
+        Self {
+            transfer_size,
+            decompressed_size,
+            content_encoding,
+            compression_ratio,
+        }
+    }
+
+    /// Indica se a resposta chegou comprimida.
+    pub fn is_compressed(&self) -> bool {
+        self.content_encoding
+            .as_deref()
+            .map(|e| !e.eq_ignore_ascii_case("identity"))
+            .unwrap_or(false)
+    }
+}
+
+pub struct PerformanceAnalyzer;
+
+impl PerformanceAnalyzer {
+    /// Descomprime o corpo conforme o `Content-Encoding` da resposta.
+    /// Codificações ausentes ou `identity` são devolvidas intactas.
+    pub fn decode(
+        encoding: Option<&str>,
+        bytes: &[u8],
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        match encoding.map(|e| e.trim().to_ascii_lowercase()).as_deref() {
+            Some("gzip") | Some("x-gzip") => {
+                let mut decoder = flate2::read::GzDecoder::new(bytes);
+                let mut out = Vec::new();
+                decoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            Some("deflate") => {
+                let mut decoder = flate2::read::ZlibDecoder::new(bytes);
+                let mut out = Vec::new();
+                decoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            Some("br") => {
+                let mut decoder = brotli::Decompressor::new(bytes, 4096);
+                let mut out = Vec::new();
+                decoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            Some("zstd") => {
+                let mut decoder = zstd::stream::read::Decoder::new(bytes)?;
+                let mut out = Vec::new();
+                decoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            _ => Ok(bytes.to_vec()),
+        }
+    }
+
+    /// Emite um alerta quando uma resposta de texto de tamanho relevante é
+    /// servida sem compressão — um problema comum e de fácil correção.
+    pub fn compression_issue(report: &PerformanceReport, content_type: Option<&str>) -> Option<SeoIssue> {
+        let is_text = content_type
+            .map(|ct| {
+                let ct = ct.to_ascii_lowercase();
+                ct.contains("text/")
+                    || ct.contains("javascript")
+                    || ct.contains("json")
+                    || ct.contains("xml")
+                    || ct.contains("svg")
+            })
+            .unwrap_or(true);
+
+        if !is_text || report.is_compressed() || report.transfer_size < COMPRESSION_MIN_SIZE {
+            return None;
+        }
+
+        let severity = if report.transfer_size >= 50 * 1_024 {
+            IssueSeverity::Warning
+        } else {
+            IssueSeverity::Info
+        };
+
+        Some(SeoIssue {
+            severity,
+            message: format!(
+                "Resposta de texto servida sem compressão ({} KB)",
+                report.transfer_size / 1_024
+            ),
+            recommendation: "Ative compressão gzip, brotli ou zstd no servidor para reduzir o peso de transferência".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder =
+            flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn gzipped_body_reports_transfer_smaller_than_decompressed() {
+        let original = "texto repetido ".repeat(500).into_bytes();
+        let wire = gzip(&original);
+        assert!(wire.len() < original.len());
+
+        let decoded = PerformanceAnalyzer::decode(Some("gzip"), &wire).unwrap();
+        assert_eq!(decoded, original);
+
+        let report = PerformanceReport::new(
+            wire.len() as u64,
+            decoded.len() as u64,
+            Some("gzip".to_string()),
+        );
+        assert!(report.transfer_size < report.decompressed_size);
+        assert!(report.compression_ratio > 1.0);
+        assert!(report.is_compressed());
+        assert!(
+            PerformanceAnalyzer::compression_issue(&report, Some("text/html")).is_none(),
+            "resposta comprimida não deve gerar alerta"
+        );
+    }
+
+    #[test]
+    fn uncompressed_text_response_is_flagged() {
+        let report = PerformanceReport::new(10_000, 10_000, None);
+        let issue = PerformanceAnalyzer::compression_issue(&report, Some("text/html; charset=utf-8"));
+        assert!(issue.is_some());
+    }
+
+    #[test]
+    fn identity_encoding_returns_body_unchanged() {
+        let body = b"conteudo simples";
+        let decoded = PerformanceAnalyzer::decode(Some("identity"), body).unwrap();
+        assert_eq!(decoded, body);
+    }
+}
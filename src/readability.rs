@@ -0,0 +1,163 @@
+use ego_tree::NodeId;
+use scraper::{Html, Node};
+use std::collections::HashMap;
+
+/// Elementos de bloco considerados candidatos a conteúdo principal.
+const CANDIDATE_TAGS: [&str; 4] = ["p", "div", "article", "section"];
+
+/// Tags cujo conteúdo nunca faz parte do artigo.
+const STRIP_TAGS: [&str; 3] = ["script", "style", "nav"];
+
+const NEGATIVE: [&str; 5] = ["nav", "sidebar", "footer", "comment", "ad"];
+const POSITIVE: [&str; 4] = ["article", "content", "post", "entry"];
+
+/// Isola o conteúdo principal de um documento antes da análise de
+/// palavras-chave, usando uma heurística de pontuação inspirada no
+/// algoritmo de readability.
+pub struct Readability;
+
+impl Readability {
+    /// Retorna o texto limpo do bloco de conteúdo principal do documento.
+    ///
+    /// Caso nenhum candidato se destaque, devolve o texto do `<body>`
+    /// (ou do documento inteiro) já sem `<script>`, `<style>` e `<nav>`.
+    pub fn extract_article_text(document: &Html) -> String {
+        let mut scores: HashMap<NodeId, f32> = HashMap::new();
+
+        for node in document.tree.nodes() {
+            let element = match node.value() {
+                Node::Element(el) => el,
+                _ => continue,
+            };
+            let tag = element.name();
+            if !CANDIDATE_TAGS.contains(&tag) {
+                continue;
+            }
+
+            let text = Self::node_text(document, node.id());
+            let text_len = text.trim().len();
+            if text_len < 25 {
+                continue;
+            }
+
+            let mut score = 1.0;
+            score += text.matches(',').count() as f32;
+            score += (text_len as f32 / 100.0).min(3.0);
+            score += Self::class_id_weight(element);
+
+            // Propaga a pontuação ao pai (inteira) e ao avô (metade): o
+            // contêiner que agrega mais texto relevante tende a vencer.
+            if let Some(parent) = node.parent() {
+                *scores.entry(parent.id()).or_insert(0.0) += score;
+                if let Some(grandparent) = parent.parent() {
+                    *scores.entry(grandparent.id()).or_insert(0.0) += score * 0.5;
+                }
+            }
+            *scores.entry(node.id()).or_insert(0.0) += score;
+        }
+
+        let top = scores
+            .iter()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(id, score)| (*id, *score));
+
+        let (top_id, top_score) = match top {
+            Some(t) => t,
+            None => return Self::body_text(document),
+        };
+
+        // Inclui o nó de maior pontuação e os irmãos acima do limiar.
+        let threshold = (top_score * 0.2).max(1.0);
+        let top_node = match document.tree.get(top_id) {
+            Some(n) => n,
+            None => return Self::body_text(document),
+        };
+
+        let mut parts: Vec<String> = Vec::new();
+        let siblings: Box<dyn Iterator<Item = _>> = match top_node.parent() {
+            Some(parent) => Box::new(parent.children()),
+            None => Box::new(std::iter::once(top_node)),
+        };
+
+        for sibling in siblings {
+            let keep = sibling.id() == top_id
+                || scores.get(&sibling.id()).copied().unwrap_or(0.0) >= threshold;
+            if keep {
+                parts.push(Self::node_text(document, sibling.id()));
+            }
+        }
+
+        let article = parts.join(" ");
+        if article.trim().is_empty() {
+            Self::body_text(document)
+        } else {
+            article
+        }
+    }
+
+    /// Peso derivado dos atributos `class`/`id` do elemento.
+    fn class_id_weight(element: &scraper::node::Element) -> f32 {
+        let mut identifier = String::new();
+        if let Some(class) = element.attr("class") {
+            identifier.push_str(&class.to_lowercase());
+        }
+        if let Some(id) = element.attr("id") {
+            identifier.push(' ');
+            identifier.push_str(&id.to_lowercase());
+        }
+
+        let mut weight = 0.0;
+        if NEGATIVE.iter().any(|n| identifier.contains(n)) {
+            weight -= 3.0;
+        }
+        if POSITIVE.iter().any(|p| identifier.contains(p)) {
+            weight += 3.0;
+        }
+        weight
+    }
+
+    /// Texto concatenado de uma subárvore, ignorando `<script>`,
+    /// `<style>` e `<nav>`.
+    fn node_text(document: &Html, id: NodeId) -> String {
+        let node = match document.tree.get(id) {
+            Some(n) => n,
+            None => return String::new(),
+        };
+
+        let mut out = String::new();
+        for descendant in node.descendants() {
+            if let Node::Text(text) = descendant.value() {
+                // Pula texto dentro de `<script>`, `<style>` ou `<nav>`.
+                if Self::in_stripped_subtree(descendant) {
+                    continue;
+                }
+                out.push_str(text);
+                out.push(' ');
+            }
+        }
+        out
+    }
+
+    fn in_stripped_subtree(node: ego_tree::NodeRef<Node>) -> bool {
+        let mut current = Some(node);
+        while let Some(n) = current {
+            if let Node::Element(el) = n.value() {
+                if STRIP_TAGS.contains(&el.name()) {
+                    return true;
+                }
+            }
+            current = n.parent();
+        }
+        false
+    }
+
+    fn body_text(document: &Html) -> String {
+        use scraper::Selector;
+        let selector = Selector::parse("body").unwrap();
+        if let Some(body) = document.select(&selector).next() {
+            Self::node_text(document, body.id())
+        } else {
+            Self::node_text(document, document.tree.root().id())
+        }
+    }
+}
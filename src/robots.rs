@@ -0,0 +1,277 @@
+use url::Url;
+
+/// Uma regra `Allow`/`Disallow` dentro de um grupo de `User-agent`.
+#[derive(Debug, Clone)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+/// Um grupo de diretivas associado a um ou mais `User-agent`.
+#[derive(Debug, Clone)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+    crawl_delay: Option<f64>,
+}
+
+/// Representação analisada de um arquivo `robots.txt`.
+#[derive(Debug, Clone, Default)]
+pub struct RobotsTxt {
+    groups: Vec<Group>,
+    /// URLs de sitemap declaradas via linhas `Sitemap:`.
+    pub sitemaps: Vec<String>,
+}
+
+impl RobotsTxt {
+    /// Baixa e analisa o `robots.txt` do host da URL informada.
+    ///
+    /// Um `robots.txt` ausente (404) é tratado como "tudo permitido",
+    /// conforme o comportamento padrão de crawlers.
+    pub async fn fetch(
+        client: &reqwest::Client,
+        url: &str,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let base = Url::parse(url)?;
+        let robots_url = base.join("/robots.txt")?;
+
+        let response = client.get(robots_url).send().await?;
+        if !response.status().is_success() {
+            return Ok(Self::default());
+        }
+
+        let body = response.text().await?;
+        Ok(Self::parse(&body))
+    }
+
+    /// Analisa o conteúdo de um `robots.txt`.
+    pub fn parse(body: &str) -> Self {
+        let mut robots = RobotsTxt::default();
+        let mut current: Option<Group> = None;
+        // Agrupa `User-agent` consecutivos: uma linha de regra encerra a
+        // sequência de agentes iniciada pelo grupo corrente.
+        let mut expecting_agent = false;
+
+        for raw in body.lines() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (field, value) = match line.split_once(':') {
+                Some((f, v)) => (f.trim().to_lowercase(), v.trim().to_string()),
+                None => continue,
+            };
+
+            match field.as_str() {
+                "user-agent" => {
+                    if !expecting_agent {
+                        if let Some(group) = current.take() {
+                            robots.groups.push(group);
+                        }
+                        current = Some(Group {
+                            agents: Vec::new(),
+                            rules: Vec::new(),
+                            crawl_delay: None,
+                        });
+                        expecting_agent = true;
+                    }
+                    if let Some(ref mut group) = current {
+                        group.agents.push(value.to_lowercase());
+                    }
+                }
+                "allow" | "disallow" => {
+                    expecting_agent = false;
+                    if let Some(ref mut group) = current {
+                        group.rules.push(Rule {
+                            allow: field == "allow",
+                            pattern: value,
+                        });
+                    }
+                }
+                "crawl-delay" => {
+                    expecting_agent = false;
+                    if let Some(ref mut group) = current {
+                        group.crawl_delay = value.parse().ok();
+                    }
+                }
+                "sitemap" => {
+                    robots.sitemaps.push(value);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(group) = current.take() {
+            robots.groups.push(group);
+        }
+
+        robots
+    }
+
+    /// Retorna `true` se `user_agent` pode buscar `url`.
+    ///
+    /// Seleciona o grupo mais específico para o agente (com recuo para `*`)
+    /// e aplica a regra `Allow`/`Disallow` de maior prefixo correspondente,
+    /// com desempate a favor de `Allow`.
+    pub fn is_allowed(&self, user_agent: &str, url: &str) -> bool {
+        let path = match Url::parse(url) {
+            Ok(u) => {
+                let mut p = u.path().to_string();
+                if let Some(q) = u.query() {
+                    p.push('?');
+                    p.push_str(q);
+                }
+                p
+            }
+            Err(_) => url.to_string(),
+        };
+
+        let group = match self.select_group(user_agent) {
+            Some(g) => g,
+            None => return true,
+        };
+
+        let mut best: Option<(usize, bool)> = None;
+        for rule in &group.rules {
+            if rule.pattern.is_empty() {
+                // `Disallow:` vazio significa permitir tudo — sem efeito.
+                continue;
+            }
+            if let Some(len) = path_matches(&rule.pattern, &path) {
+                let better = match best {
+                    Some((best_len, best_allow)) => {
+                        len > best_len || (len == best_len && rule.allow && !best_allow)
+                    }
+                    None => true,
+                };
+                if better {
+                    best = Some((len, rule.allow));
+                }
+            }
+        }
+
+        best.map(|(_, allow)| allow).unwrap_or(true)
+    }
+
+    /// O `Crawl-delay` aplicável ao agente, se houver.
+    pub fn crawl_delay(&self, user_agent: &str) -> Option<f64> {
+        self.select_group(user_agent).and_then(|g| g.crawl_delay)
+    }
+
+    fn select_group(&self, user_agent: &str) -> Option<&Group> {
+        let ua = user_agent.to_lowercase();
+        let mut best: Option<(usize, &Group)> = None;
+
+        for group in &self.groups {
+            for agent in &group.agents {
+                let score = if agent == "*" {
+                    0
+                } else if ua.contains(agent.as_str()) {
+                    agent.len()
+                } else {
+                    continue;
+                };
+                if best.map(|(s, _)| score > s).unwrap_or(true) {
+                    best = Some((score, group));
+                }
+            }
+        }
+
+        best.map(|(_, g)| g)
+    }
+}
+
+/// Verifica se `pattern` (com curingas `*` e âncora `$`) casa com `path`.
+/// Retorna o comprimento do padrão (sem os metacaracteres) em caso positivo,
+/// usado como medida de especificidade.
+fn path_matches(pattern: &str, path: &str) -> Option<usize> {
+    let anchored = pattern.ends_with('$');
+    let pat = if anchored {
+        &pattern[..pattern.len() - 1]
+    } else {
+        pattern
+    };
+
+    // Comprimento de especificidade: caracteres literais do padrão.
+    let specificity = pat.chars().filter(|c| *c != '*').count();
+
+    let segments: Vec<&str> = pat.split('*').collect();
+    let mut pos = 0usize;
+
+    for (i, seg) in segments.iter().enumerate() {
+        if seg.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            // O padrão é ancorado no início do caminho.
+            if !path[pos..].starts_with(seg) {
+                return None;
+            }
+            pos += seg.len();
+        } else {
+            match path[pos..].find(seg) {
+                Some(idx) => pos += idx + seg.len(),
+                None => return None,
+            }
+        }
+    }
+
+    if anchored && pos != path.len() {
+        return None;
+    }
+
+    Some(specificity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_match_returns_specificity() {
+        assert_eq!(path_matches("/blog", "/blog/post"), Some(5));
+        assert_eq!(path_matches("/blog", "/about"), None);
+    }
+
+    #[test]
+    fn wildcard_and_end_anchor() {
+        assert!(path_matches("/*.php", "/index.php").is_some());
+        assert!(path_matches("/*.php$", "/index.php").is_some());
+        assert!(path_matches("/*.php$", "/index.php?q=1").is_none());
+        assert_eq!(path_matches("/a*b", "/axxb"), Some(3));
+    }
+
+    #[test]
+    fn longest_prefix_wins_with_allow_tiebreak() {
+        let robots = RobotsTxt::parse(
+            "User-agent: *\nDisallow: /dir/\nAllow: /dir/public/\n",
+        );
+        assert!(!robots.is_allowed("bot", "https://s.com/dir/secret"));
+        assert!(robots.is_allowed("bot", "https://s.com/dir/public/page"));
+    }
+
+    #[test]
+    fn empty_disallow_allows_everything() {
+        let robots = RobotsTxt::parse("User-agent: *\nDisallow:\n");
+        assert!(robots.is_allowed("bot", "https://s.com/anything"));
+    }
+
+    #[test]
+    fn most_specific_group_selected() {
+        let robots = RobotsTxt::parse(
+            "User-agent: *\nDisallow: /\n\nUser-agent: googlebot\nDisallow:\n",
+        );
+        assert!(robots.is_allowed("Googlebot/2.1", "https://s.com/x"));
+        assert!(!robots.is_allowed("randombot", "https://s.com/x"));
+    }
+
+    #[test]
+    fn crawl_delay_and_sitemaps_are_captured() {
+        let robots = RobotsTxt::parse(
+            "User-agent: *\nCrawl-delay: 5\nSitemap: https://s.com/sitemap.xml\n",
+        );
+        assert_eq!(robots.crawl_delay("bot"), Some(5.0));
+        assert_eq!(robots.sitemaps, vec!["https://s.com/sitemap.xml".to_string()]);
+    }
+}
@@ -1,5 +1,11 @@
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+
+/// Future empacotado devolvido por [`SitemapGenerator::fetch_into`] para
+/// permitir a recursão assíncrona com tamanho conhecido.
+type FetchFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), Box<dyn std::error::Error>>> + 'a>>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SitemapUrl {
@@ -14,43 +20,304 @@ pub struct Sitemap {
     pub urls: Vec<SitemapUrl>,
 }
 
+/// Limites para evitar buscas descontroladas ao seguir `<sitemapindex>`.
+#[derive(Debug, Clone)]
+pub struct FetchLimits {
+    /// Número máximo de URLs acumuladas em todos os sitemaps.
+    pub max_urls: usize,
+    /// Profundidade máxima de recursão por índices de sitemap.
+    pub max_depth: usize,
+}
+
+impl Default for FetchLimits {
+    fn default() -> Self {
+        Self {
+            max_urls: 50_000,
+            max_depth: 5,
+        }
+    }
+}
+
 pub struct SitemapGenerator;
 
 impl SitemapGenerator {
     pub fn generate_xml(urls: &[SitemapUrl]) -> String {
         let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
         xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
-        
+
         for url in urls {
             xml.push_str("  <url>\n");
             xml.push_str(&format!("    <loc>{}</loc>\n", url.loc));
-            
+
             if let Some(ref lastmod) = url.lastmod {
                 xml.push_str(&format!("    <lastmod>{}</lastmod>\n", lastmod));
             }
-            
+
             if let Some(ref changefreq) = url.changefreq {
                 xml.push_str(&format!("    <changefreq>{}</changefreq>\n", changefreq));
             }
-            
+
             if let Some(priority) = url.priority {
                 xml.push_str(&format!("    <priority>{:.1}</priority>\n", priority));
             }
-            
+
             xml.push_str("  </url>\n");
         }
-        
+
         xml.push_str("</urlset>");
         xml
     }
 
     pub async fn fetch_sitemap(url: &str) -> Result<Sitemap, Box<dyn std::error::Error>> {
         let client = reqwest::Client::new();
-        let response = client.get(url).send().await?;
-        let content = response.text().await?;
-        
-        // Aqui você implementaria o parsing do XML do sitemap
-        // Por simplicidade, retornando um sitemap vazio
-        Ok(Sitemap { urls: Vec::new() })
+        Self::fetch_sitemap_with(&client, url, &FetchLimits::default()).await
+    }
+
+    /// Busca e analisa um sitemap, seguindo recursivamente os índices
+    /// (`<sitemapindex>`) até os limites configurados.
+    pub async fn fetch_sitemap_with(
+        client: &reqwest::Client,
+        url: &str,
+        limits: &FetchLimits,
+    ) -> Result<Sitemap, Box<dyn std::error::Error>> {
+        let mut sitemap = Sitemap { urls: Vec::new() };
+        Self::fetch_into(client, url, limits, 0, &mut sitemap).await?;
+        Ok(sitemap)
+    }
+
+    // A recursão assíncrona precisa ser empacotada em um `Box` para ter
+    // tamanho conhecido em tempo de compilação.
+    fn fetch_into<'a>(
+        client: &'a reqwest::Client,
+        url: &'a str,
+        limits: &'a FetchLimits,
+        depth: usize,
+        sitemap: &'a mut Sitemap,
+    ) -> FetchFuture<'a> {
+        Box::pin(async move {
+            if depth > limits.max_depth || sitemap.urls.len() >= limits.max_urls {
+                return Ok(());
+            }
+
+            let response = client.get(url).send().await?;
+            let bytes = response.bytes().await?;
+            let body = Self::decompress_if_needed(url, &bytes)?;
+
+            match Self::parse_xml(&body)? {
+                ParsedSitemap::Urlset(urls) => {
+                    for u in urls {
+                        if sitemap.urls.len() >= limits.max_urls {
+                            break;
+                        }
+                        sitemap.urls.push(u);
+                    }
+                }
+                ParsedSitemap::Index(children) => {
+                    for child in children {
+                        if sitemap.urls.len() >= limits.max_urls {
+                            break;
+                        }
+                        // Falhas em um filho não devem abortar o índice inteiro.
+                        let _ =
+                            Self::fetch_into(client, &child, limits, depth + 1, sitemap).await;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn decompress_if_needed(
+        url: &str,
+        bytes: &[u8],
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if url.ends_with(".gz") {
+            use std::io::Read;
+            let mut decoder = flate2::read::GzDecoder::new(bytes);
+            let mut out = String::new();
+            decoder.read_to_string(&mut out)?;
+            Ok(out)
+        } else {
+            Ok(String::from_utf8_lossy(bytes).into_owned())
+        }
+    }
+
+    fn parse_xml(body: &str) -> Result<ParsedSitemap, Box<dyn std::error::Error>> {
+        let mut reader = Reader::from_str(body);
+        reader.config_mut().trim_text(true);
+
+        let mut urls: Vec<SitemapUrl> = Vec::new();
+        let mut index_locs: Vec<String> = Vec::new();
+
+        let mut in_sitemapindex = false;
+        let mut current: Option<SitemapUrl> = None;
+        let mut current_loc = String::new();
+        let mut tag: Option<Tag> = None;
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"sitemapindex" => in_sitemapindex = true,
+                    b"url" => {
+                        current = Some(SitemapUrl {
+                            loc: String::new(),
+                            lastmod: None,
+                            changefreq: None,
+                            priority: None,
+                        })
+                    }
+                    b"sitemap" => current_loc.clear(),
+                    b"loc" => tag = Some(Tag::Loc),
+                    b"lastmod" => tag = Some(Tag::Lastmod),
+                    b"changefreq" => tag = Some(Tag::Changefreq),
+                    b"priority" => tag = Some(Tag::Priority),
+                    _ => tag = None,
+                },
+                Event::Text(e) => {
+                    let text = e.unescape()?.into_owned();
+                    match tag {
+                        Some(Tag::Loc) => {
+                            if in_sitemapindex {
+                                current_loc = text;
+                            } else if let Some(ref mut u) = current {
+                                u.loc = text;
+                            }
+                        }
+                        Some(Tag::Lastmod) => {
+                            if let Some(ref mut u) = current {
+                                u.lastmod = Some(text);
+                            }
+                        }
+                        Some(Tag::Changefreq) => {
+                            if let Some(ref mut u) = current {
+                                u.changefreq = Some(text);
+                            }
+                        }
+                        Some(Tag::Priority) => {
+                            if let Some(ref mut u) = current {
+                                u.priority = text.trim().parse().ok();
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                Event::End(e) => match e.local_name().as_ref() {
+                    b"url" => {
+                        if let Some(u) = current.take() {
+                            if !u.loc.is_empty() {
+                                urls.push(u);
+                            }
+                        }
+                    }
+                    b"sitemap" if !current_loc.is_empty() => {
+                        index_locs.push(std::mem::take(&mut current_loc));
+                    }
+                    b"loc" | b"lastmod" | b"changefreq" | b"priority" => tag = None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        if in_sitemapindex {
+            Ok(ParsedSitemap::Index(index_locs))
+        } else {
+            Ok(ParsedSitemap::Urlset(urls))
+        }
+    }
+}
+
+enum ParsedSitemap {
+    Urlset(Vec<SitemapUrl>),
+    Index(Vec<String>),
+}
+
+enum Tag {
+    Loc,
+    Lastmod,
+    Changefreq,
+    Priority,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn parses_urlset_with_optional_fields() {
+        let xml = r#"<?xml version="1.0"?>
+        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+          <url>
+            <loc>https://s.com/a</loc>
+            <lastmod>2024-01-01</lastmod>
+            <changefreq>daily</changefreq>
+            <priority>0.8</priority>
+          </url>
+          <url><loc>https://s.com/b</loc></url>
+        </urlset>"#;
+
+        match SitemapGenerator::parse_xml(xml).unwrap() {
+            ParsedSitemap::Urlset(urls) => {
+                assert_eq!(urls.len(), 2);
+                assert_eq!(urls[0].loc, "https://s.com/a");
+                assert_eq!(urls[0].changefreq.as_deref(), Some("daily"));
+                assert_eq!(urls[0].priority, Some(0.8));
+                assert_eq!(urls[1].lastmod, None);
+            }
+            ParsedSitemap::Index(_) => panic!("esperava urlset"),
+        }
+    }
+
+    #[test]
+    fn parses_sitemapindex() {
+        let xml = r#"<?xml version="1.0"?>
+        <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+          <sitemap><loc>https://s.com/s1.xml</loc></sitemap>
+          <sitemap><loc>https://s.com/s2.xml</loc></sitemap>
+        </sitemapindex>"#;
+
+        match SitemapGenerator::parse_xml(xml).unwrap() {
+            ParsedSitemap::Index(locs) => {
+                assert_eq!(locs, vec!["https://s.com/s1.xml", "https://s.com/s2.xml"]);
+            }
+            ParsedSitemap::Urlset(_) => panic!("esperava sitemapindex"),
+        }
+    }
+
+    #[test]
+    fn decompresses_gzipped_sitemap() {
+        let xml = r#"<urlset><url><loc>https://s.com/a</loc></url></urlset>"#;
+        let mut encoder =
+            flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(xml.as_bytes()).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        let body = SitemapGenerator::decompress_if_needed("https://s.com/sitemap.xml.gz", &gz)
+            .unwrap();
+        assert_eq!(body, xml);
+    }
+
+    #[test]
+    fn round_trips_through_generate_xml() {
+        let urls = vec![SitemapUrl {
+            loc: "https://s.com/a".to_string(),
+            lastmod: None,
+            changefreq: None,
+            priority: Some(0.5),
+        }];
+        let xml = SitemapGenerator::generate_xml(&urls);
+        match SitemapGenerator::parse_xml(&xml).unwrap() {
+            ParsedSitemap::Urlset(parsed) => {
+                assert_eq!(parsed.len(), 1);
+                assert_eq!(parsed[0].loc, "https://s.com/a");
+                assert_eq!(parsed[0].priority, Some(0.5));
+            }
+            ParsedSitemap::Index(_) => panic!("esperava urlset"),
+        }
     }
 }
@@ -0,0 +1,227 @@
+use crate::{IssueSeverity, SeoIssue};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Visão tipada de um item de dados estruturados `application/ld+json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredDataItem {
+    /// Valor de `@type`, quando presente.
+    pub item_type: Option<String>,
+    /// JSON do item, reserializado de forma compacta.
+    pub raw: String,
+}
+
+/// Resultado da análise dos blocos de dados estruturados de uma página.
+#[derive(Debug, Clone)]
+pub struct StructuredDataResult {
+    pub items: Vec<StructuredDataItem>,
+    pub issues: Vec<SeoIssue>,
+}
+
+pub struct StructuredDataAnalyzer;
+
+impl StructuredDataAnalyzer {
+    /// Analisa cada bloco `ld+json`, tolerando arrays e contêineres
+    /// `@graph`, valida as propriedades obrigatórias dos tipos comuns e
+    /// devolve os itens tipados junto com os problemas encontrados.
+    pub fn analyze(blocks: &[String]) -> StructuredDataResult {
+        let mut items = Vec::new();
+        let mut issues = Vec::new();
+
+        for block in blocks {
+            let value: Value = match serde_json::from_str(block.trim()) {
+                Ok(v) => v,
+                Err(_) => {
+                    issues.push(SeoIssue {
+                        severity: IssueSeverity::Warning,
+                        message: "Bloco JSON-LD inválido".to_string(),
+                        recommendation: "Corrija a sintaxe do JSON no script application/ld+json".to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            for (object, has_context) in Self::flatten(&value, false) {
+                Self::analyze_object(&object, has_context, &mut items, &mut issues);
+            }
+        }
+
+        StructuredDataResult { items, issues }
+    }
+
+    /// Desdobra um valor em objetos individuais, seguindo arrays de nível
+    /// superior e contêineres `@graph`.
+    ///
+    /// Em JSON-LD o `@context` vive no invólucro de nível superior e os
+    /// membros de `@graph` o herdam, então propagamos `inherited` para que
+    /// nós aninhados não sejam sinalizados como se estivessem sem contexto.
+    fn flatten(value: &Value, inherited: bool) -> Vec<(Value, bool)> {
+        match value {
+            Value::Array(arr) => arr
+                .iter()
+                .flat_map(|v| Self::flatten(v, inherited))
+                .collect(),
+            Value::Object(map) => {
+                let has_context = inherited || map.contains_key("@context");
+                if let Some(graph) = map.get("@graph") {
+                    return Self::flatten(graph, has_context);
+                }
+                vec![(value.clone(), has_context)]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn analyze_object(
+        object: &Value,
+        has_context: bool,
+        items: &mut Vec<StructuredDataItem>,
+        issues: &mut Vec<SeoIssue>,
+    ) {
+        let item_type = Self::extract_type(object);
+
+        if !has_context {
+            issues.push(SeoIssue {
+                severity: IssueSeverity::Warning,
+                message: "Dados estruturados sem @context".to_string(),
+                recommendation: "Declare \"@context\": \"https://schema.org\" no item JSON-LD".to_string(),
+            });
+        }
+
+        if let Some(ref ty) = item_type {
+            for field in Self::missing_required(ty, object) {
+                issues.push(SeoIssue {
+                    severity: IssueSeverity::Warning,
+                    message: format!("{} sem a propriedade obrigatória \"{}\"", ty, field),
+                    recommendation: format!(
+                        "Adicione \"{}\" ao item {} para elegibilidade a rich results",
+                        field, ty
+                    ),
+                });
+            }
+        }
+
+        items.push(StructuredDataItem {
+            item_type,
+            raw: object.to_string(),
+        });
+    }
+
+    fn extract_type(object: &Value) -> Option<String> {
+        match object.get("@type") {
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(Value::Array(arr)) => {
+                let joined: Vec<String> = arr
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect();
+                if joined.is_empty() {
+                    None
+                } else {
+                    Some(joined.join(", "))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Propriedades obrigatórias ausentes para os tipos que validamos.
+    fn missing_required(item_type: &str, object: &Value) -> Vec<&'static str> {
+        let required: &[&str] = match item_type {
+            "Article" | "NewsArticle" | "BlogPosting" => {
+                &["headline", "author", "datePublished"]
+            }
+            "Product" => &["name", "offers"],
+            "BreadcrumbList" => &["itemListElement"],
+            _ => return Vec::new(),
+        };
+
+        let mut missing = Vec::new();
+        for field in required {
+            match object.get(*field) {
+                Some(value) if !Self::is_empty(value) => {}
+                _ => missing.push(*field),
+            }
+        }
+        missing
+    }
+
+    fn is_empty(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(s) => s.trim().is_empty(),
+            Value::Array(a) => a.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(result: &StructuredDataResult) -> Vec<String> {
+        result.issues.iter().map(|i| i.message.clone()).collect()
+    }
+
+    #[test]
+    fn graph_members_inherit_top_level_context() {
+        let block = r#"{
+            "@context": "https://schema.org",
+            "@graph": [
+                {"@type": "WebSite", "name": "Exemplo"},
+                {"@type": "WebPage", "name": "Página"}
+            ]
+        }"#;
+        let result = StructuredDataAnalyzer::analyze(&[block.to_string()]);
+        assert_eq!(result.items.len(), 2);
+        assert!(
+            !messages(&result)
+                .iter()
+                .any(|m| m.contains("sem @context")),
+            "membros de @graph herdam o @context do invólucro"
+        );
+    }
+
+    #[test]
+    fn missing_context_is_reported_once() {
+        let block = r#"{"@type": "WebPage", "name": "x"}"#;
+        let result = StructuredDataAnalyzer::analyze(&[block.to_string()]);
+        assert_eq!(
+            messages(&result)
+                .iter()
+                .filter(|m| m.contains("sem @context"))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn article_missing_required_fields_is_flagged() {
+        let block = r#"{"@context": "https://schema.org", "@type": "Article", "headline": "Oi"}"#;
+        let result = StructuredDataAnalyzer::analyze(&[block.to_string()]);
+        let msgs = messages(&result);
+        assert!(msgs.iter().any(|m| m.contains("author")));
+        assert!(msgs.iter().any(|m| m.contains("datePublished")));
+        assert!(!msgs.iter().any(|m| m.contains("headline")));
+    }
+
+    #[test]
+    fn complete_product_has_no_issues() {
+        let block = r#"{
+            "@context": "https://schema.org",
+            "@type": "Product",
+            "name": "Tênis",
+            "offers": {"@type": "Offer", "price": "99.90"}
+        }"#;
+        let result = StructuredDataAnalyzer::analyze(&[block.to_string()]);
+        assert!(result.issues.is_empty(), "{:?}", messages(&result));
+        assert_eq!(result.items[0].item_type.as_deref(), Some("Product"));
+    }
+
+    #[test]
+    fn invalid_json_is_reported() {
+        let result = StructuredDataAnalyzer::analyze(&["{not json".to_string()]);
+        assert!(messages(&result).iter().any(|m| m.contains("inválido")));
+    }
+}